@@ -0,0 +1,24 @@
+//! Compares `precompute`/`extend` timings between the serial and `rayon`
+//! parallel code paths. Run with `cargo bench --bench ecfft` for the serial
+//! baseline, and `cargo bench --bench ecfft --features parallel` to see the
+//! effect of the work-stealing backend.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use ecfft::{
+    bn254::Bn254Parameters,
+    ecfft::{EcFftParameters, EcFftPrecomputationStep},
+};
+
+fn bench_precompute(c: &mut Criterion) {
+    c.bench_function("precompute", |b| b.iter(Bn254Parameters::precompute));
+}
+
+fn bench_extend(c: &mut Criterion) {
+    let precomputation = Bn254Parameters::precompute();
+    let EcFftPrecomputationStep { s, .. } = &precomputation.steps[0];
+    let evals: Vec<_> = s.to_vec();
+    c.bench_function("extend", |b| b.iter(|| precomputation.extend(&evals)));
+}
+
+criterion_group!(benches, bench_precompute, bench_extend);
+criterion_main!(benches);
@@ -1,15 +1,18 @@
 use std::convert::TryInto;
 
-use crate::{ecfft::EcFftParameters, utils::isogeny::Isogeny};
-use ark_ff::BigInteger384;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use crate::{
+    ecfft::EcFftParameters,
+    utils::{field_parser::FieldParser, isogeny::Isogeny},
+};
 
 type F = ark_bls12_381::Fq;
-/// Number of 64-bit limbs needed to represent field elements.
-const NUM_LIMBS: usize = 6;
 
 /// ECFFT parameters for the BLS12-381 base field `F`.
 /// Computed with the curve `E = EllipticCurve(F, [a, b])` with
 /// `a, b = 0x287cc81c41f14f729fcbc12f57b2dd49bdcfc64938f9ad946c9fe5288aa3e9653670d336b09c058baad66ae717c1df7, 0x33f44f9b6fd7ba0080f0ad4843e076da70b11e6846d41e19792a15a4920e2294f9c971db67257eefea71c70514c6e54`
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
 pub struct Bls12381Parameters;
 
 impl EcFftParameters<F> for Bls12381Parameters {
@@ -21,53 +24,26 @@ impl EcFftParameters<F> for Bls12381Parameters {
 
     /// Get the coset from the `bls12-381_coset` file. This file can be generated by running `get_params.sage`.
     fn coset() -> Vec<F> {
-        std::fs::read_to_string("bls12-381_coset")
+        let limbs: Vec<u64> = std::fs::read_to_string("bls12-381_coset")
             .expect("Run `get_params.sage` to generate the coset.")
             .split_whitespace()
             .map(|s| s.parse().unwrap())
-            .collect::<Vec<u64>>()
-            .chunks(NUM_LIMBS)
-            .map(|chunk| BigInteger384::new(chunk.try_into().unwrap()).into())
-            .collect()
+            .collect();
+        FieldParser::parse_elements(&limbs)
     }
 
     /// Get the isogenies from the `bls12-381_isogenies` file. This file can be generated by running `get_params.sage`.
     fn isogenies() -> Vec<Isogeny<F>> {
-        std::fs::read_to_string("bls12-381_isogenies")
-            .expect("Run `get_params.sage` to generate the coset.")
+        let limbs: Vec<u64> = std::fs::read_to_string("bls12-381_isogenies")
+            .expect("Run `get_params.sage` to generate the isogenies.")
             .split_whitespace()
             .map(|s| s.parse().unwrap())
-            .collect::<Vec<u64>>()
-            .chunks(5 * NUM_LIMBS)
-            .map(|chunk| {
-                let numerator = (0..3)
-                    .map(|i| {
-                        BigInteger384::new(
-                            chunk[i * NUM_LIMBS..(i + 1) * NUM_LIMBS]
-                                .try_into()
-                                .unwrap(),
-                        )
-                        .into()
-                    })
-                    .collect::<Vec<_>>()
-                    .try_into()
-                    .unwrap();
-                let denominator = (3..5)
-                    .map(|i| {
-                        BigInteger384::new(
-                            chunk[i * NUM_LIMBS..(i + 1) * NUM_LIMBS]
-                                .try_into()
-                                .unwrap(),
-                        )
-                        .into()
-                    })
-                    .collect::<Vec<_>>()
-                    .try_into()
-                    .unwrap();
-                Isogeny {
-                    numerator,
-                    denominator,
-                }
+            .collect();
+        FieldParser::parse_records::<F>(&limbs, 5)
+            .into_iter()
+            .map(|record| Isogeny {
+                numerator: record[0..3].try_into().unwrap(),
+                denominator: record[3..5].try_into().unwrap(),
             })
             .collect()
     }
@@ -125,22 +101,49 @@ mod tests {
     }
 
     #[test]
-    /// Tests the `evaluate_over_domain` function for various degrees.
+    /// Tests the `evaluate_over_domain` function against direct evaluation
+    /// over the full coset.
     fn test_eval() {
         type P = Bls12381Parameters;
         let precomputation = P::precompute();
-        for i in 0..P::LOG_N {
-            let mut rng = test_rng();
-            let coeffs: Vec<F> = (0..P::N >> i).map(|_| rng.gen()).collect();
-            let poly = DensePolynomial { coeffs };
-            let now = std::time::Instant::now();
-            let evals = P::sub_coset(i)
-                .iter()
-                .map(|x| poly.evaluate(x))
-                .collect::<Vec<_>>();
-            dbg!(now.elapsed().as_secs_f32());
-            assert_eq!(evals, precomputation.evaluate_over_domain(&poly));
-            dbg!(now.elapsed().as_secs_f32());
+        let mut rng = test_rng();
+        let coeffs: Vec<F> = (0..P::N).map(|_| rng.gen()).collect();
+        let poly = DensePolynomial { coeffs };
+        let evals = P::coset()
+            .iter()
+            .map(|x| poly.evaluate(x))
+            .collect::<Vec<_>>();
+        assert_eq!(evals, precomputation.evaluate_over_domain(&poly));
+    }
+
+    #[test]
+    /// Tests that `interpolate_over_domain` (EXIT) inverts `evaluate_over_domain` (ENTER).
+    fn test_interpolate() {
+        type P = Bls12381Parameters;
+        let precomputation = P::precompute();
+        let mut rng = test_rng();
+        let coeffs: Vec<F> = (0..P::N).map(|_| rng.gen()).collect();
+        let poly = DensePolynomial { coeffs };
+        let evals = precomputation.evaluate_over_domain(&poly);
+        assert_eq!(poly, precomputation.interpolate_over_domain(&evals));
+    }
+
+    #[test]
+    /// Tests `multiply_polynomials` against the naive `ark_poly` convolution
+    /// for random inputs across several degrees.
+    fn test_multiply() {
+        type P = Bls12381Parameters;
+        let precomputation = P::precompute();
+        let mut rng = test_rng();
+        for (deg_a, deg_b) in [(0, 0), (1, 1), (3, 5), (100, 200), (1000, 2000)] {
+            let a = DensePolynomial {
+                coeffs: (0..=deg_a).map(|_| rng.gen()).collect::<Vec<F>>(),
+            };
+            let b = DensePolynomial {
+                coeffs: (0..=deg_b).map(|_| rng.gen()).collect::<Vec<F>>(),
+            };
+            let expected = a.naive_mul(&b);
+            assert_eq!(expected, precomputation.multiply_polynomials(&a, &b));
         }
     }
 }
@@ -0,0 +1,147 @@
+use std::convert::TryInto;
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use crate::{
+    ecfft::EcFftParameters,
+    utils::{field_parser::FieldParser, isogeny::Isogeny},
+};
+
+pub type F = ark_bn254::Fq;
+
+/// ECFFT parameters for the BN254 base field `F`.
+/// Computed with the curve `E = EllipticCurve(F, [a, b])` with
+/// `a, b` chosen so that `E` has a `2^LOG_N`-smooth factor in its order.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct Bn254Parameters;
+
+impl EcFftParameters<F> for Bn254Parameters {
+    const LOG_N: usize = 15;
+
+    const N: usize = 1 << Self::LOG_N;
+
+    /// Get the coset from the `bn254_coset` file. This file can be generated by running `get_params.sage`.
+    fn coset() -> Vec<F> {
+        let limbs: Vec<u64> = std::fs::read_to_string("bn254_coset")
+            .expect("Run `get_params.sage` to generate the coset.")
+            .split_whitespace()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        FieldParser::parse_elements(&limbs)
+    }
+
+    /// Get the isogenies from the `bn254_isogenies` file. This file can be generated by running `get_params.sage`.
+    fn isogenies() -> Vec<Isogeny<F>> {
+        let limbs: Vec<u64> = std::fs::read_to_string("bn254_isogenies")
+            .expect("Run `get_params.sage` to generate the isogenies.")
+            .split_whitespace()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        FieldParser::parse_records::<F>(&limbs, 5)
+            .into_iter()
+            .map(|record| Isogeny {
+                numerator: record[0..3].try_into().unwrap(),
+                denominator: record[3..5].try_into().unwrap(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ecfft::{EcFftCosetPrecomputation, EcFftParameters, EcFftPrecomputationStep};
+
+    use super::{Bn254Parameters, F};
+    use ark_ff::PrimeField;
+    use ark_poly::{univariate::DensePolynomial, Polynomial};
+    use ark_std::{
+        rand::{distributions::Standard, prelude::Distribution, Rng},
+        test_rng,
+    };
+
+    #[test]
+    /// Tests that precomputations don't panic.
+    fn test_precompute() {
+        Bn254Parameters::precompute_on_coset(&Bn254Parameters::coset());
+        Bn254Parameters::precompute_on_coset(
+            &Bn254Parameters::coset()
+                .into_iter()
+                .step_by(2)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    /// Tests the extend function with a polynomial of degree `2^i - 1`.
+    fn test_extend_i<F: PrimeField, P: EcFftParameters<F>>(
+        i: usize,
+        precomputation: &EcFftCosetPrecomputation<F, P>,
+    ) where
+        Standard: Distribution<F>,
+    {
+        let n = 1 << i;
+        let mut rng = test_rng();
+        let coeffs: Vec<F> = (0..n).map(|_| rng.gen()).collect();
+        let poly = DensePolynomial { coeffs };
+        let EcFftPrecomputationStep { s, s_prime, .. } =
+            &precomputation.steps[Bn254Parameters::LOG_N - 1 - i];
+        let evals_s = s.iter().map(|x| poly.evaluate(x)).collect::<Vec<_>>();
+        let evals_s_prime = s_prime.iter().map(|x| poly.evaluate(x)).collect::<Vec<_>>();
+        assert_eq!(evals_s_prime, precomputation.extend(&evals_s));
+    }
+
+    #[test]
+    /// Tests the extend function for various degrees.
+    fn test_extend() {
+        let precomputation = Bn254Parameters::precompute_on_coset(&Bn254Parameters::coset());
+        for i in 1..Bn254Parameters::LOG_N {
+            test_extend_i::<F, _>(i, &precomputation);
+        }
+    }
+
+    #[test]
+    /// Tests the `evaluate_over_domain` function against direct evaluation
+    /// over the full coset.
+    fn test_eval() {
+        type P = Bn254Parameters;
+        let precomputation = P::precompute();
+        let mut rng = test_rng();
+        let coeffs: Vec<F> = (0..P::N).map(|_| rng.gen()).collect();
+        let poly = DensePolynomial { coeffs };
+        let evals = P::coset()
+            .iter()
+            .map(|x| poly.evaluate(x))
+            .collect::<Vec<_>>();
+        assert_eq!(evals, precomputation.evaluate_over_domain(&poly));
+    }
+
+    #[test]
+    /// Tests that `interpolate_over_domain` inverts `evaluate_over_domain`.
+    fn test_interpolate() {
+        type P = Bn254Parameters;
+        let precomputation = P::precompute();
+        let mut rng = test_rng();
+        let coeffs: Vec<F> = (0..P::N).map(|_| rng.gen()).collect();
+        let poly = DensePolynomial { coeffs };
+        let evals = precomputation.evaluate_over_domain(&poly);
+        assert_eq!(poly, precomputation.interpolate_over_domain(&evals));
+    }
+
+    #[test]
+    /// Tests `multiply_polynomials` against the naive `ark_poly` convolution
+    /// for random inputs across several degrees.
+    fn test_multiply() {
+        type P = Bn254Parameters;
+        let precomputation = P::precompute();
+        let mut rng = test_rng();
+        for (deg_a, deg_b) in [(0, 0), (1, 1), (3, 5), (100, 200), (1000, 2000)] {
+            let a = DensePolynomial {
+                coeffs: (0..=deg_a).map(|_| rng.gen()).collect::<Vec<F>>(),
+            };
+            let b = DensePolynomial {
+                coeffs: (0..=deg_b).map(|_| rng.gen()).collect::<Vec<F>>(),
+            };
+            let expected = a.naive_mul(&b);
+            assert_eq!(expected, precomputation.multiply_polynomials(&a, &b));
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use std::marker::PhantomData;
+
+use ark_ff::PrimeField;
+use ark_poly::{univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::utils::{isogeny::Isogeny, matrix::Matrix};
+
+/// Parameters defining an ECFFT-friendly coset chain over the field `F`.
+///
+/// An implementor fixes a curve `E/F` with a chain of `LOG_N` rational
+/// 2-isogenies and a coset `L` of `E(F)`-related points of size `N = 2^LOG_N`
+/// on which `E`'s Fiber splits evenly; see [`EcFftPrecomputationStep`] for
+/// the shape of the recursion built on top of these.
+pub trait EcFftParameters<F: PrimeField>:
+    Sized + Send + Sync + CanonicalSerialize + CanonicalDeserialize
+{
+    /// `log2` of the size of the top-level coset.
+    const LOG_N: usize;
+
+    /// Size of the top-level coset, i.e. `1 << LOG_N`.
+    const N: usize;
+
+    /// The top-level coset `L`, of size `N`.
+    fn coset() -> Vec<F>;
+
+    /// The chain of `LOG_N` degree-2 isogenies, ordered from the top level
+    /// (mapping the size-`N` coset to the size-`N/2` one) down to the last
+    /// level (mapping a size-2 coset to a single point).
+    fn isogenies() -> Vec<Isogeny<F>>;
+
+    /// The sub-coset obtained by keeping every `2^i`-th point of [`coset`],
+    /// suitable for evaluating a polynomial of degree `< N >> i`.
+    fn sub_coset(i: usize) -> Vec<F> {
+        Self::coset().into_iter().step_by(1 << i).collect()
+    }
+
+    /// Precomputation for the top-level coset.
+    fn precompute() -> EcFftCosetPrecomputation<F, Self> {
+        Self::precompute_on_coset(&Self::coset())
+    }
+
+    /// Precomputation for an arbitrary power-of-two-sized coset, which need
+    /// not be the full top-level one (e.g. a sub-coset of it).
+    fn precompute_on_coset(coset: &[F]) -> EcFftCosetPrecomputation<F, Self> {
+        let n = coset.len();
+        assert!(n.is_power_of_two(), "coset size must be a power of two");
+        let log_n = n.trailing_zeros() as usize;
+        assert!(
+            log_n <= Self::LOG_N,
+            "coset is larger than the top-level one"
+        );
+        let isogenies = Self::isogenies();
+        let steps = build_steps::<F, Self>(coset, &isogenies[Self::LOG_N - log_n..]);
+        EcFftCosetPrecomputation {
+            coset: coset.to_vec(),
+            steps,
+            _parameters: PhantomData,
+        }
+    }
+}
+
+/// Precomputed data for one level of the ECFFT recursion: the splitting of a
+/// domain `L` of size `2^k` into the two sub-cosets `S`, `S'` of size
+/// `2^{k-1}` that both map, under this level's [`Isogeny`], onto the same
+/// smaller domain `L'`.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct EcFftPrecomputationStep<F, P>
+where
+    F: PrimeField,
+    P: EcFftParameters<F>,
+{
+    /// First half of `L`.
+    pub s: Vec<F>,
+    /// Second half of `L`, paired index-for-index with `s` over `L'`.
+    pub s_prime: Vec<F>,
+    /// `matrices[i]` relates `(P0(l'_i), P1(l'_i))` to `(P(s_i), P(s'_i))`.
+    pub matrices: Vec<Matrix<F>>,
+    /// `matrices[i].inverse()`, cached since every EXIT step needs it.
+    pub inverse_matrices: Vec<Matrix<F>>,
+    _parameters: PhantomData<P>,
+}
+
+fn build_steps<F: PrimeField, P: EcFftParameters<F>>(
+    domain: &[F],
+    isogenies: &[Isogeny<F>],
+) -> Vec<EcFftPrecomputationStep<F, P>> {
+    let n = domain.len();
+    if n == 1 {
+        return Vec::new();
+    }
+    let half = n / 2;
+    let s = domain[..half].to_vec();
+    let s_prime = domain[half..].to_vec();
+
+    #[cfg(feature = "parallel")]
+    let matrices: Vec<Matrix<F>> = s
+        .par_iter()
+        .zip(s_prime.par_iter())
+        .map(|(&x, &y)| Matrix([[F::one(), x], [F::one(), y]]))
+        .collect();
+    #[cfg(not(feature = "parallel"))]
+    let matrices: Vec<Matrix<F>> = s
+        .iter()
+        .zip(s_prime.iter())
+        .map(|(&x, &y)| Matrix([[F::one(), x], [F::one(), y]]))
+        .collect();
+
+    #[cfg(feature = "parallel")]
+    let inverse_matrices = matrices.par_iter().map(Matrix::inverse).collect();
+    #[cfg(not(feature = "parallel"))]
+    let inverse_matrices = matrices.iter().map(Matrix::inverse).collect();
+
+    let l_prime: Vec<F> = s.iter().map(|x| isogenies[0].eval(*x)).collect();
+    let mut steps = vec![EcFftPrecomputationStep {
+        s,
+        s_prime,
+        matrices,
+        inverse_matrices,
+        _parameters: PhantomData,
+    }];
+    steps.extend(build_steps::<F, P>(&l_prime, &isogenies[1..]));
+    steps
+}
+
+/// Precomputation for running the ECFFT ENTER/EXIT transforms, and the
+/// standalone low-degree extension `extend`, on a given coset.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct EcFftCosetPrecomputation<F, P>
+where
+    F: PrimeField,
+    P: EcFftParameters<F>,
+{
+    pub coset: Vec<F>,
+    pub steps: Vec<EcFftPrecomputationStep<F, P>>,
+    _parameters: PhantomData<P>,
+}
+
+impl<F: PrimeField, P: EcFftParameters<F>> EcFftCosetPrecomputation<F, P> {
+    pub(crate) fn step_for_len(&self, n: usize) -> &EcFftPrecomputationStep<F, P> {
+        self.steps
+            .iter()
+            .find(|step| step.s.len() == n)
+            .expect("no precomputed level matches this evaluation length")
+    }
+
+    /// Low-degree-extends the evaluations of a polynomial of degree
+    /// `< evals.len()` from `S` to the paired coset `S'` at the matching
+    /// level, i.e. the ECFFT analogue of a Reed-Solomon LDE.
+    pub fn extend(&self, evals: &[F]) -> Vec<F> {
+        let step = self.step_for_len(evals.len());
+        let poly = DensePolynomial::from_coefficients_vec(lagrange_interpolate(&step.s, evals));
+        #[cfg(feature = "parallel")]
+        let points = step.s_prime.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let points = step.s_prime.iter();
+        points.map(|x| poly.evaluate(x)).collect()
+    }
+
+    /// ENTER: evaluates `poly` (degree `< coset.len()`) over the full coset.
+    pub fn evaluate_over_domain(&self, poly: &DensePolynomial<F>) -> Vec<F> {
+        assert!(poly.coeffs.len() <= self.coset.len());
+        enter(&self.steps, &poly.coeffs)
+    }
+
+    /// EXIT: recovers the coefficients of the unique polynomial of degree
+    /// `< evals.len()` agreeing with `evals` on the coset, the inverse of
+    /// [`Self::evaluate_over_domain`].
+    pub fn interpolate_over_domain(&self, evals: &[F]) -> DensePolynomial<F> {
+        assert_eq!(evals.len(), self.coset.len());
+        DensePolynomial::from_coefficients_vec(exit(&self.steps, evals))
+    }
+
+    /// Multiplies `a` and `b` by evaluating both on the smallest domain of
+    /// the recursion large enough to hold the product's degree, multiplying pointwise, and running EXIT
+    /// to recover the product's coefficients. This replaces the `O(n^2)`
+    /// schoolbook convolution `ark_poly` falls back to when `F` has no
+    /// smooth-order root of unity for a radix-2 FFT.
+    pub fn multiply_polynomials(
+        &self,
+        a: &DensePolynomial<F>,
+        b: &DensePolynomial<F>,
+    ) -> DensePolynomial<F> {
+        if a.coeffs.is_empty() || b.coeffs.is_empty() {
+            return DensePolynomial::from_coefficients_vec(vec![]);
+        }
+        let product_len = a.coeffs.len() + b.coeffs.len() - 1;
+        let target_len = product_len.next_power_of_two();
+        assert!(
+            target_len <= self.coset.len(),
+            "product degree exceeds this precomputation's coset size"
+        );
+        let log_n = self.steps.len();
+        let t = target_len.trailing_zeros() as usize;
+        let sub_steps = &self.steps[log_n - t..];
+
+        let evals_a = enter(sub_steps, &a.coeffs);
+        let evals_b = enter(sub_steps, &b.coeffs);
+        let evals_product: Vec<F> = evals_a
+            .iter()
+            .zip(evals_b.iter())
+            .map(|(&x, &y)| x * y)
+            .collect();
+        DensePolynomial::from_coefficients_vec(exit(sub_steps, &evals_product))
+    }
+
+    /// Serializes the coset, isogeny-derived sub-cosets and cached per-step
+    /// `Matrix` inverses, so the (potentially expensive) precomputation can
+    /// be shipped to a target with no filesystem access, e.g. a WASM prover.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.compressed_size());
+        self.serialize_compressed(&mut bytes)
+            .expect("writing to a Vec<u8> cannot fail");
+        bytes
+    }
+
+    /// Inverse of [`Self::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerializationError> {
+        Self::deserialize_compressed(bytes)
+    }
+}
+
+/// Naive `O(n^2)` Lagrange interpolation, used by [`EcFftCosetPrecomputation::extend`].
+pub(crate) fn lagrange_interpolate<F: PrimeField>(points: &[F], values: &[F]) -> Vec<F> {
+    let mut coeffs = vec![F::zero(); points.len()];
+    for (i, (&xi, &yi)) in points.iter().zip(values).enumerate() {
+        let mut term = vec![F::one()];
+        let mut denom = F::one();
+        for (j, &xj) in points.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            // term *= (x - xj)
+            let mut next = vec![F::zero(); term.len() + 1];
+            for (k, &c) in term.iter().enumerate() {
+                next[k + 1] += c;
+                next[k] -= c * xj;
+            }
+            term = next;
+            denom *= xi - xj;
+        }
+        let scale = yi / denom;
+        for (c, t) in coeffs.iter_mut().zip(term.iter()) {
+            *c += scale * t;
+        }
+    }
+    coeffs
+}
+
+/// Applies `matrices[i]` to `(a[i], b[i])` in place, for every pair. Each
+/// pair is independent, so with the `parallel` feature this is split across
+/// rayon's work-stealing thread pool instead of running as a single loop.
+fn apply_matrices_in_place<F: PrimeField>(matrices: &[Matrix<F>], a: &mut [F], b: &mut [F]) {
+    #[cfg(feature = "parallel")]
+    {
+        matrices
+            .par_iter()
+            .zip(a.par_iter_mut().zip(b.par_iter_mut()))
+            .for_each(|(matrix, (x, y))| matrix.multiply_in_place(x, y));
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        matrices
+            .iter()
+            .zip(a.iter_mut().zip(b.iter_mut()))
+            .for_each(|(matrix, (x, y))| matrix.multiply_in_place(x, y));
+    }
+}
+
+/// Recursive ENTER: splits `coeffs` into even/odd halves `P0, P1`, evaluates
+/// each on the next level's domain `L'`, then recombines pointwise via this
+/// level's [`Matrix`] to read off the evaluations on `S` and `S'`.
+fn enter<F: PrimeField, P: EcFftParameters<F>>(
+    steps: &[EcFftPrecomputationStep<F, P>],
+    coeffs: &[F],
+) -> Vec<F> {
+    let Some((step, rest)) = steps.split_first() else {
+        return vec![coeffs.first().copied().unwrap_or_else(F::zero)];
+    };
+    let n = step.s.len() + step.s_prime.len();
+    let mut padded = coeffs.to_vec();
+    padded.resize(n, F::zero());
+    let p0: Vec<F> = padded.iter().step_by(2).copied().collect();
+    let p1: Vec<F> = padded.iter().skip(1).step_by(2).copied().collect();
+
+    let mut s_evals = enter(rest, &p0);
+    let mut s_prime_evals = enter(rest, &p1);
+    apply_matrices_in_place(&step.matrices, &mut s_evals, &mut s_prime_evals);
+    s_evals.into_iter().chain(s_prime_evals).collect()
+}
+
+/// Recursive EXIT: the exact reverse of [`enter`]. At each level, inverts
+/// this level's [`Matrix`] pointwise to recover the evaluations of `P0, P1`
+/// on `L'`, recurses on each half, then interleaves the resulting
+/// coefficient vectors.
+fn exit<F: PrimeField, P: EcFftParameters<F>>(
+    steps: &[EcFftPrecomputationStep<F, P>],
+    evals: &[F],
+) -> Vec<F> {
+    let Some((step, rest)) = steps.split_first() else {
+        return vec![evals[0]];
+    };
+    let half = step.s.len();
+    let (evals_s, evals_s_prime) = evals.split_at(half);
+
+    let mut p0_evals = evals_s.to_vec();
+    let mut p1_evals = evals_s_prime.to_vec();
+    apply_matrices_in_place(&step.inverse_matrices, &mut p0_evals, &mut p1_evals);
+
+    let c0 = exit(rest, &p0_evals);
+    let c1 = exit(rest, &p1_evals);
+    let mut coeffs = vec![F::zero(); 2 * half];
+    for i in 0..half {
+        coeffs[2 * i] = c0[i];
+        coeffs[2 * i + 1] = c1[i];
+    }
+    coeffs
+}
@@ -0,0 +1,14 @@
+//! `ecfft-bn254`: ECFFT (elliptic-curve FFT) over base fields, such as those
+//! of BN254 and BLS12-381, that have no large smooth-order multiplicative
+//! subgroup and are therefore unreachable by a classical radix-2 FFT.
+//!
+//! The construction follows Ben-Sasson, Kopparty and Saraf's "ECFFT" papers:
+//! a chain of 2-isogenies between elliptic curves over `F` is used to build a
+//! recursively self-similar evaluation domain, giving an `O(N log^2 N)`
+//! evaluate/interpolate pair in place of the usual roots-of-unity FFT.
+
+pub mod bls12_381;
+pub mod bn254;
+pub mod ecfft;
+pub mod reed_solomon;
+pub mod utils;
@@ -0,0 +1,140 @@
+//! Systematic Reed-Solomon erasure coding built on the ECFFT low-degree
+//! extension, analogous to the KZG+RS data-availability encoders that turn a
+//! message into redundant polynomial evaluations.
+
+use std::fmt;
+
+use ark_ff::PrimeField;
+use ark_poly::{univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+
+use crate::ecfft::{lagrange_interpolate, EcFftCosetPrecomputation, EcFftParameters};
+
+/// A rate-1/2 systematic Reed-Solomon code over the coset of an
+/// [`EcFftCosetPrecomputation`]: a message of `k` symbols is treated as the
+/// evaluations of a degree-`<k` polynomial on one sub-coset `S`, and the
+/// codeword is `S`'s evaluations followed by the parity evaluations on the
+/// complementary coset `S'`.
+pub struct ReedSolomon<'a, F: PrimeField, P: EcFftParameters<F>> {
+    precomputation: &'a EcFftCosetPrecomputation<F, P>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReedSolomonError {
+    /// Fewer than `k` codeword symbols survived to decode from.
+    NotEnoughSymbols { needed: usize, got: usize },
+}
+
+impl fmt::Display for ReedSolomonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotEnoughSymbols { needed, got } => write!(
+                f,
+                "not enough surviving symbols to decode: need {needed}, got {got}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReedSolomonError {}
+
+impl<'a, F: PrimeField, P: EcFftParameters<F>> ReedSolomon<'a, F, P> {
+    pub fn new(precomputation: &'a EcFftCosetPrecomputation<F, P>) -> Self {
+        Self { precomputation }
+    }
+
+    /// Encodes `message` (`k = message.len()`, a power of two) into a
+    /// codeword of length `2k`: `message` itself followed by its `k` parity
+    /// symbols on the complementary coset `S'`.
+    pub fn encode(&self, message: &[F]) -> Vec<F> {
+        let parity = self.precomputation.extend(message);
+        let mut codeword = message.to_vec();
+        codeword.extend(parity);
+        codeword
+    }
+
+    /// Recovers the `k` message symbols from any `k` surviving positions of
+    /// a codeword produced by [`Self::encode`]. Surviving positions need not
+    /// be contiguous or lie entirely within `S` or `S'`.
+    pub fn decode(&self, codeword: &[Option<F>]) -> Result<Vec<F>, ReedSolomonError> {
+        let k = codeword.len() / 2;
+        let step = self.precomputation.step_for_len(k);
+        let points = step.s.iter().chain(step.s_prime.iter());
+        let pairs: Vec<(F, F)> = points
+            .zip(codeword.iter())
+            .filter_map(|(&x, value)| value.map(|y| (x, y)))
+            .collect();
+        if pairs.len() < k {
+            return Err(ReedSolomonError::NotEnoughSymbols {
+                needed: k,
+                got: pairs.len(),
+            });
+        }
+        let (xs, ys): (Vec<F>, Vec<F>) = pairs.into_iter().take(k).unzip();
+        let poly = DensePolynomial::from_coefficients_vec(lagrange_interpolate(&xs, &ys));
+        Ok(step.s.iter().map(|x| poly.evaluate(x)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReedSolomon, ReedSolomonError};
+    use crate::{
+        bn254::{Bn254Parameters, F},
+        ecfft::EcFftParameters,
+    };
+    use ark_std::{rand::Rng, test_rng};
+
+    #[test]
+    /// Erases random subsets of a codeword and confirms exact recovery as
+    /// long as at least `k` symbols survive.
+    fn test_encode_decode() {
+        let precomputation = Bn254Parameters::precompute();
+        let rs = ReedSolomon::new(&precomputation);
+        let mut rng = test_rng();
+        let k = 64;
+        let message: Vec<F> = (0..k).map(|_| rng.gen()).collect();
+        let codeword = rs.encode(&message);
+        assert_eq!(codeword.len(), 2 * k);
+
+        let mut erased: Vec<Option<F>> = codeword.iter().copied().map(Some).collect();
+        let mut indices: Vec<usize> = (0..codeword.len()).collect();
+        // Erase a random k of the 2k symbols, leaving exactly k survivors.
+        for &i in indices_to_erase(&mut rng, &mut indices, k).iter() {
+            erased[i] = None;
+        }
+        assert_eq!(rs.decode(&erased).unwrap(), message);
+    }
+
+    #[test]
+    /// Fewer than `k` survivors must be rejected rather than silently
+    /// producing a wrong answer.
+    fn test_decode_rejects_too_few_survivors() {
+        let precomputation = Bn254Parameters::precompute();
+        let rs = ReedSolomon::new(&precomputation);
+        let mut rng = test_rng();
+        let k = 64;
+        let message: Vec<F> = (0..k).map(|_| rng.gen()).collect();
+        let mut codeword: Vec<Option<F>> = rs.encode(&message).into_iter().map(Some).collect();
+        // Erase one more than the code can tolerate.
+        let mut indices: Vec<usize> = (0..codeword.len()).collect();
+        for &i in indices_to_erase(&mut rng, &mut indices, k + 1).iter() {
+            codeword[i] = None;
+        }
+        assert_eq!(
+            rs.decode(&codeword),
+            Err(ReedSolomonError::NotEnoughSymbols {
+                needed: k,
+                got: k - 1,
+            })
+        );
+    }
+
+    /// Shuffles `indices` and returns the first `count` of them to erase.
+    fn indices_to_erase(rng: &mut impl Rng, indices: &mut [usize], count: usize) -> Vec<usize> {
+        for i in (1..indices.len()).rev() {
+            let j = rng.gen_range(0..=i);
+            indices.swap(i, j);
+        }
+        indices[..count].to_vec()
+    }
+}
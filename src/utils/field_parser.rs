@@ -0,0 +1,75 @@
+use ark_ff::{BigInteger, PrimeField};
+
+/// Reconstructs field elements for any [`PrimeField`] from a flat stream of
+/// `u64` limbs, the representation `get_params.sage` emits for a coset or
+/// isogeny file.
+///
+/// The limb count and `BigInteger` type are derived from `F::BigInt`, so a
+/// downstream curve's parameter module never needs to hardcode its own
+/// `NUM_LIMBS` or repeat the byte-chunking logic.
+pub struct FieldParser;
+
+impl FieldParser {
+    /// Number of 64-bit limbs needed to represent an `F` element.
+    pub fn num_limbs<F: PrimeField>() -> usize {
+        F::BigInt::NUM_LIMBS
+    }
+
+    /// Parses a flat limb stream into consecutive field elements.
+    pub fn parse_elements<F: PrimeField>(limbs: &[u64]) -> Vec<F> {
+        limbs
+            .chunks(Self::num_limbs::<F>())
+            .map(|chunk| {
+                let mut repr = F::BigInt::default();
+                repr.as_mut().copy_from_slice(chunk);
+                F::from_bigint(repr).expect("limbs should represent a valid field element")
+            })
+            .collect()
+    }
+
+    /// Parses a flat limb stream into consecutive records of
+    /// `elements_per_record` field elements each, e.g. an [`Isogeny`]'s 5
+    /// numerator/denominator coefficients.
+    ///
+    /// [`Isogeny`]: crate::utils::isogeny::Isogeny
+    pub fn parse_records<F: PrimeField>(limbs: &[u64], elements_per_record: usize) -> Vec<Vec<F>> {
+        Self::parse_elements::<F>(limbs)
+            .chunks(elements_per_record)
+            .map(<[F]>::to_vec)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FieldParser;
+    use crate::bn254::F;
+    use ark_ff::PrimeField;
+    use ark_std::{rand::Rng, test_rng};
+
+    #[test]
+    fn test_parse_elements_round_trips() {
+        let mut rng = test_rng();
+        let elements: Vec<F> = (0..8).map(|_| rng.gen()).collect();
+        let limbs: Vec<u64> = elements
+            .iter()
+            .flat_map(|e| e.into_bigint().as_ref().to_vec())
+            .collect();
+        assert_eq!(FieldParser::parse_elements::<F>(&limbs), elements);
+    }
+
+    #[test]
+    fn test_parse_records_groups_by_record_size() {
+        let mut rng = test_rng();
+        let elements: Vec<F> = (0..10).map(|_| rng.gen()).collect();
+        let limbs: Vec<u64> = elements
+            .iter()
+            .flat_map(|e| e.into_bigint().as_ref().to_vec())
+            .collect();
+        let records = FieldParser::parse_records::<F>(&limbs, 5);
+        assert_eq!(
+            records,
+            elements.chunks(5).map(<[F]>::to_vec).collect::<Vec<_>>()
+        );
+    }
+}
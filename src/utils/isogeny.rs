@@ -0,0 +1,26 @@
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+/// A degree-2 rational map `\psi(x) = (n0 + n1 x + n2 x^2) / (d0 + d1 x)`.
+///
+/// Each step of the ECFFT recursion uses one of these to project a level's
+/// domain `L` onto the domain `L'` of the next level down, in the same way
+/// that `x -> x^2` projects a multiplicative subgroup onto its square in a
+/// classical radix-2 FFT.
+#[derive(Clone, Copy, Debug, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Isogeny<F>
+where
+    F: Sized + Send + Sync + Copy + CanonicalDeserialize + CanonicalSerialize,
+{
+    pub numerator: [F; 3],
+    pub denominator: [F; 2],
+}
+
+impl<F: Field> Isogeny<F> {
+    /// Evaluate the isogeny at `x`.
+    pub fn eval(&self, x: F) -> F {
+        let [n0, n1, n2] = self.numerator;
+        let [d0, d1] = self.denominator;
+        (n0 + n1 * x + n2 * x * x) / (d0 + d1 * x)
+    }
+}
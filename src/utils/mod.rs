@@ -0,0 +1,3 @@
+pub mod field_parser;
+pub mod isogeny;
+pub mod matrix;